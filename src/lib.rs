@@ -12,11 +12,19 @@ extern crate alloc;
 #[cfg(test)] mod test;
 
 // core
-use core::{convert::TryInto, mem, result::Result as CoreResult};
+use core::{
+	convert::{TryFrom, TryInto},
+	fmt,
+	mem,
+	ops::Deref,
+	result::Result as CoreResult,
+	str::FromStr,
+};
 // alloc
 use alloc::{string::String, vec::Vec};
 // crates.io
-#[cfg(feature = "serde")] use serde::{de::Error as DeError, Deserialize, Deserializer};
+#[cfg(feature = "serde")]
+use serde::{de::Error as DeError, Deserialize, Deserializer, Serialize, Serializer};
 // use thiserror::Error as ThisError;
 
 /// The main result of array-bytes.
@@ -41,9 +49,17 @@ macro_rules! impl_num_from_hex {
 			where
 				H: AsRef<str>,
 			{
-				let hex = strip_0x(hex.as_ref());
+				// `strip_0x` only strips the `0x` prefix, so the sign (if any) must be peeled
+				// off first and reattached afterwards; this keeps negative values parsing as
+				// sign+magnitude instead of overflowing `from_str_radix`.
+				let (negative, hex) = match hex.as_ref().strip_prefix('-') {
+					Some(hex) => (true, hex),
+					None => (false, hex.as_ref()),
+				};
+				let hex = strip_0x(hex);
+				let hex = if negative { alloc::format!("-{hex}") } else { String::from(hex) };
 
-				Self::from_str_radix(hex, 16).map_err(Error::ParseIntError)
+				Self::from_str_radix(&hex, 16).map_err(Error::ParseIntError)
 			}
 		}
 	};
@@ -61,10 +77,138 @@ impl_num_from_hex!(u32);
 impl_num_from_hex!(u64);
 impl_num_from_hex!(u128);
 
+/// Simple and safe `T`/[`String`] conversions to hex.
+pub trait ToHex {
+	/// Convert [`Self`] to hex.
+	fn to_hex(&self, prefix: &str) -> String;
+}
+impl ToHex for [u8] {
+	fn to_hex(&self, prefix: &str) -> String {
+		bytes2hex(prefix, self)
+	}
+}
+impl<const N: usize> ToHex for [u8; N] {
+	fn to_hex(&self, prefix: &str) -> String {
+		bytes2hex(prefix, self)
+	}
+}
+impl ToHex for Vec<u8> {
+	fn to_hex(&self, prefix: &str) -> String {
+		bytes2hex(prefix, self)
+	}
+}
+
+macro_rules! impl_signed_num_to_hex {
+	($t:ty) => {
+		impl ToHex for $t {
+			fn to_hex(&self, prefix: &str) -> String {
+				// `{:x}` formats negative integers as their two's-complement bit pattern, which
+				// doesn't round-trip through `TryFromHex` (sign+magnitude). Emit an explicit `-`
+				// and the magnitude's hex instead, matching what `try_from_hex` expects.
+				if *self < 0 {
+					alloc::format!("-{prefix}{:x}", self.unsigned_abs())
+				} else {
+					alloc::format!("{prefix}{self:x}")
+				}
+			}
+		}
+	};
+}
+impl_signed_num_to_hex!(isize);
+impl_signed_num_to_hex!(i8);
+impl_signed_num_to_hex!(i16);
+impl_signed_num_to_hex!(i32);
+impl_signed_num_to_hex!(i64);
+impl_signed_num_to_hex!(i128);
+
+macro_rules! impl_num_to_hex {
+	($t:ty) => {
+		impl ToHex for $t {
+			fn to_hex(&self, prefix: &str) -> String {
+				alloc::format!("{prefix}{self:x}")
+			}
+		}
+	};
+}
+impl_num_to_hex!(usize);
+impl_num_to_hex!(u8);
+impl_num_to_hex!(u16);
+impl_num_to_hex!(u32);
+impl_num_to_hex!(u64);
+impl_num_to_hex!(u128);
+
+/// Simple and safe fixed-width `T`/`AsRef<[u8]>` conversions with explicit endianness.
+pub trait NumBytes
+where
+	Self: Sized,
+{
+	/// The fixed-width byte array representation of [`Self`].
+	type Bytes;
+
+	/// Try to convert a big-endian byte slice to [`Self`].
+	fn bytes2num_be<B>(bytes: B) -> Result<Self>
+	where
+		B: AsRef<[u8]>;
+
+	/// Try to convert a little-endian byte slice to [`Self`].
+	fn bytes2num_le<B>(bytes: B) -> Result<Self>
+	where
+		B: AsRef<[u8]>;
+
+	/// Convert [`Self`] to its big-endian byte representation.
+	fn num2bytes_be(self) -> Self::Bytes;
+
+	/// Convert [`Self`] to its little-endian byte representation.
+	fn num2bytes_le(self) -> Self::Bytes;
+}
+
+macro_rules! impl_num_bytes {
+	($t:ty) => {
+		impl NumBytes for $t {
+			type Bytes = [u8; mem::size_of::<$t>()];
+
+			fn bytes2num_be<B>(bytes: B) -> Result<Self>
+			where
+				B: AsRef<[u8]>,
+			{
+				Ok(Self::from_be_bytes(slice2array(bytes.as_ref())?))
+			}
+
+			fn bytes2num_le<B>(bytes: B) -> Result<Self>
+			where
+				B: AsRef<[u8]>,
+			{
+				Ok(Self::from_le_bytes(slice2array(bytes.as_ref())?))
+			}
+
+			fn num2bytes_be(self) -> Self::Bytes {
+				self.to_be_bytes()
+			}
+
+			fn num2bytes_le(self) -> Self::Bytes {
+				self.to_le_bytes()
+			}
+		}
+	};
+}
+impl_num_bytes!(isize);
+impl_num_bytes!(i8);
+impl_num_bytes!(i16);
+impl_num_bytes!(i32);
+impl_num_bytes!(i64);
+impl_num_bytes!(i128);
+impl_num_bytes!(usize);
+impl_num_bytes!(u8);
+impl_num_bytes!(u16);
+impl_num_bytes!(u32);
+impl_num_bytes!(u64);
+impl_num_bytes!(u128);
+
 /// The main error of array-bytes.
 #[derive(Debug, PartialEq, Eq)]
 pub enum Error {
-	/// The length must not be odd.
+	/// The length is invalid: hex input must have an even length, and (unpadded) base64 input
+	/// must not have a length of `4n + 1`.
 	InvalidLength,
 	/// Found the invalid character at `index`.
 	InvalidCharacter {
@@ -271,6 +415,54 @@ pub unsafe fn hex_bytes2hex_str_unchecked(bytes: &[u8]) -> &str {
 	mem::transmute(bytes)
 }
 
+/// Lazily iterate over the hex characters (without prefix) of `bytes`.
+///
+/// # Examples
+/// ```
+/// assert_eq!(
+/// 	array_bytes::hex_chars(b"Love Jane Forever").collect::<String>(),
+/// 	"4c6f7665204a616e6520466f7265766572"
+/// );
+/// ```
+pub fn hex_chars(bytes: &[u8]) -> impl Iterator<Item = char> + '_ {
+	bytes.iter().flat_map(|byte| {
+		[
+			char::from_digit((byte >> 4) as _, 16).unwrap(),
+			char::from_digit((byte & 0xf) as _, 16).unwrap(),
+		]
+	})
+}
+
+/// Just like [`bytes2hex`] but writes directly into a [`fmt::Write`] sink instead of allocating a
+/// [`String`].
+///
+/// This lets [`fmt::Display`] impls (e.g. [`Hex`]) and `no_std` callers writing into a fixed,
+/// on-stack buffer format hex without a heap allocation.
+///
+/// # Examples
+/// ```
+/// use core::fmt::Write;
+///
+/// let mut hex = String::new();
+///
+/// array_bytes::bytes2hex_write("0x", b"Love Jane Forever", &mut hex).unwrap();
+///
+/// assert_eq!(hex, "0x4c6f7665204a616e6520466f7265766572");
+/// ```
+pub fn bytes2hex_write<W, B>(prefix: &str, bytes: B, out: &mut W) -> fmt::Result
+where
+	W: fmt::Write,
+	B: AsRef<[u8]>,
+{
+	out.write_str(prefix)?;
+
+	for c in hex_chars(bytes.as_ref()) {
+		out.write_char(c)?;
+	}
+
+	Ok(())
+}
+
 /// `AsRef<[u8]>` to [`String`].
 ///
 /// # Examples
@@ -287,11 +479,8 @@ where
 	let bytes = bytes.as_ref();
 	let mut hex = String::with_capacity(prefix.len() + bytes.len() * 2);
 
-	prefix.chars().for_each(|byte| hex.push(byte));
-	bytes.iter().for_each(|byte| {
-		hex.push(char::from_digit((byte >> 4) as _, 16).unwrap());
-		hex.push(char::from_digit((byte & 0xf) as _, 16).unwrap());
-	});
+	// `String`'s `fmt::Write` impl never fails; qed.
+	bytes2hex_write(prefix, bytes, &mut hex).unwrap();
 
 	hex
 }
@@ -328,6 +517,49 @@ where
 	hex2bytes_unchecked(hex).try_into().unwrap()
 }
 
+/// Just like [`hex2array_unchecked`] but as a `const fn`, so hardcoded hex literals (genesis
+/// hashes, well-known account IDs, ...) can be decoded to an array at compile time.
+///
+/// # Examples
+/// ```
+/// const GENESIS: [u8; 17] =
+/// 	array_bytes::const_hex2array("0x4c6f7665204a616e6520466f7265766572");
+///
+/// assert_eq!(GENESIS, *b"Love Jane Forever");
+/// ```
+pub const fn const_hex2array<const N: usize>(hex: &str) -> [u8; N] {
+	let bytes = hex.as_bytes();
+	let offset = if bytes.len() >= 2 && bytes[0] == b'0' && bytes[1] == b'x' { 2 } else { 0 };
+	let len = bytes.len() - offset;
+
+	if len % 2 != 0 {
+		panic!("odd length");
+	}
+	if len != N * 2 {
+		panic!("mismatched length");
+	}
+
+	let mut array = [0_u8; N];
+	let mut i = 0;
+
+	while i < N {
+		array[i] = const_hex_ascii2digit(bytes[offset + i * 2]) << 4
+			| const_hex_ascii2digit(bytes[offset + i * 2 + 1]);
+		i += 1;
+	}
+
+	array
+}
+
+const fn const_hex_ascii2digit(byte: u8) -> u8 {
+	match byte {
+		b'0'..=b'9' => byte - b'0',
+		b'a'..=b'f' => byte - b'a' + 10,
+		b'A'..=b'F' => byte - b'A' + 10,
+		_ => panic!("invalid hex character"),
+	}
+}
+
 /// `AsRef<[u8]>` to [`Vec<u8>`].
 ///
 /// Return error if:
@@ -688,6 +920,526 @@ where
 	hex2bytes(hex).map_err(|_| D::Error::custom(alloc::format!("Invalid hex str `{}`", hex)))
 }
 
+/// Serialize `T: AsRef<[u8]>` as a hex string.
+///
+/// # Examples
+/// ```
+/// use serde::Serialize;
+///
+/// #[derive(Debug, PartialEq, Serialize)]
+/// struct LJF {
+/// 	#[serde(serialize_with = "array_bytes::ser_bytes2hex")]
+/// 	ljf: Vec<u8>,
+/// }
+///
+/// assert_eq!(
+/// 	serde_json::to_string(&LJF { ljf: b"Love Jane Forever".to_vec() }).unwrap(),
+/// 	r#"{"ljf":"0x4c6f7665204a616e6520466f7265766572"}"#
+/// );
+/// ```
+#[cfg(feature = "serde")]
+pub fn ser_bytes2hex<B, S>(bytes: &B, serializer: S) -> CoreResult<S::Ok, S::Error>
+where
+	B: AsRef<[u8]>,
+	S: Serializer,
+{
+	serializer.serialize_str(&bytes2hex("0x", bytes))
+}
+
+/// Serialize `T: ToHex` primitive num types as a hex string.
+///
+/// # Examples
+/// ```
+/// use serde::Serialize;
+///
+/// #[derive(Debug, PartialEq, Serialize)]
+/// struct LJF {
+/// 	#[serde(serialize_with = "array_bytes::ser_num2hex")]
+/// 	ljf: u32,
+/// }
+///
+/// assert_eq!(serde_json::to_string(&LJF { ljf: 1314 }).unwrap(), r#"{"ljf":"0x522"}"#);
+/// ```
+#[cfg(feature = "serde")]
+pub fn ser_num2hex<T, S>(num: &T, serializer: S) -> CoreResult<S::Ok, S::Error>
+where
+	T: ToHex,
+	S: Serializer,
+{
+	serializer.serialize_str(&num.to_hex("0x"))
+}
+
+/// Serialize any `T: ToHex` as a hex string.
+///
+/// This works for any type that implements [`ToHex`] (the same bound as [`ser_num2hex`]), e.g. a
+/// newtype wrapper.
+///
+/// # Examples
+/// ```
+/// use serde::Serialize;
+///
+/// #[derive(Debug, PartialEq, Serialize)]
+/// struct LJF {
+/// 	#[serde(serialize_with = "array_bytes::hex_serialize")]
+/// 	ljf: array_bytes::Hex,
+/// }
+///
+/// assert_eq!(
+/// 	serde_json::to_string(&LJF { ljf: array_bytes::Hex::new(b"Love Jane Forever".to_vec()) })
+/// 		.unwrap(),
+/// 	r#"{"ljf":"0x4c6f7665204a616e6520466f7265766572"}"#
+/// );
+/// ```
+#[cfg(feature = "serde")]
+pub fn hex_serialize<T, S>(value: &T, serializer: S) -> CoreResult<S::Ok, S::Error>
+where
+	T: ToHex,
+	S: Serializer,
+{
+	serializer.serialize_str(&value.to_hex("0x"))
+}
+
+/// The base64 alphabet to encode/decode with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Base64Alphabet {
+	/// The standard alphabet, `A-Za-z0-9+/`, as defined in RFC 4648 §4.
+	Standard,
+	/// The URL- and filename-safe alphabet, `A-Za-z0-9-_`, as defined in RFC 4648 §5.
+	UrlSafe,
+}
+impl Base64Alphabet {
+	fn chars(self) -> &'static [u8; 64] {
+		match self {
+			Self::Standard =>
+				b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+			Self::UrlSafe =>
+				b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_",
+		}
+	}
+
+	fn decode_table(self) -> [u8; 256] {
+		let mut table = [0xFF_u8; 256];
+		let mut i = 0;
+
+		while i < 64 {
+			table[self.chars()[i] as usize] = i as u8;
+			i += 1;
+		}
+
+		table
+	}
+}
+
+/// `AsRef<[u8]>` to [`String`], base64 encoded.
+///
+/// # Examples
+/// ```
+/// assert_eq!(
+/// 	array_bytes::bytes2base64(b"Love Jane Forever", array_bytes::Base64Alphabet::Standard),
+/// 	String::from("TG92ZSBKYW5lIEZvcmV2ZXI=")
+/// );
+/// ```
+pub fn bytes2base64<B>(bytes: B, alphabet: Base64Alphabet) -> String
+where
+	B: AsRef<[u8]>,
+{
+	let bytes = bytes.as_ref();
+	let chars = alphabet.chars();
+	let mut base64 = String::with_capacity(bytes.len().div_ceil(3) * 4);
+
+	for chunk in bytes.chunks(3) {
+		let b0 = chunk[0];
+		let b1 = chunk.get(1).copied().unwrap_or(0);
+		let b2 = chunk.get(2).copied().unwrap_or(0);
+
+		base64.push(chars[(b0 >> 2) as usize] as char);
+		base64.push(chars[(((b0 & 0b11) << 4) | (b1 >> 4)) as usize] as char);
+		base64.push(if chunk.len() > 1 {
+			chars[(((b1 & 0b1111) << 2) | (b2 >> 6)) as usize] as char
+		} else {
+			'='
+		});
+		base64.push(if chunk.len() > 2 { chars[(b2 & 0b11_1111) as usize] as char } else { '=' });
+	}
+
+	base64
+}
+
+/// Base64 to [`Vec<u8>`].
+///
+/// Accepts input with or without the `=` padding.
+///
+/// Return error if:
+/// - the (unpadded) length is `4n + 1`
+/// - encounter a character outside of the given [`Base64Alphabet`]
+///
+/// # Examples
+/// ```
+/// assert_eq!(
+/// 	array_bytes::base642bytes(
+/// 		"TG92ZSBKYW5lIEZvcmV2ZXI=",
+/// 		array_bytes::Base64Alphabet::Standard
+/// 	),
+/// 	Ok(b"Love Jane Forever".to_vec())
+/// );
+/// ```
+pub fn base642bytes<B>(base64: B, alphabet: Base64Alphabet) -> Result<Vec<u8>>
+where
+	B: AsRef<[u8]>,
+{
+	let base64 = strip_base64_padding(base64.as_ref());
+
+	if base64.len() % 4 == 1 {
+		Err(Error::InvalidLength)?;
+	}
+
+	let table = alphabet.decode_table();
+	let mut sextets = Vec::with_capacity(base64.len());
+
+	for (i, &c) in base64.iter().enumerate() {
+		let sextet = table[c as usize];
+
+		if sextet == 0xFF {
+			Err(Error::InvalidCharacter { character: c as _, index: i })?;
+		}
+
+		sextets.push(sextet);
+	}
+
+	let mut bytes = Vec::with_capacity(sextets.len() * 3 / 4);
+
+	for chunk in sextets.chunks(4) {
+		bytes.push(chunk[0] << 2 | chunk[1] >> 4);
+
+		if chunk.len() > 2 {
+			bytes.push(chunk[1] << 4 | chunk[2] >> 2);
+		}
+		if chunk.len() > 3 {
+			bytes.push(chunk[2] << 6 | chunk[3]);
+		}
+	}
+
+	Ok(bytes)
+}
+
+/// Just like [`base642bytes`] but to a fixed length array.
+///
+/// # Examples
+/// ```
+/// assert_eq!(
+/// 	array_bytes::base642array::<_, 17>(
+/// 		"TG92ZSBKYW5lIEZvcmV2ZXI=",
+/// 		array_bytes::Base64Alphabet::Standard
+/// 	),
+/// 	Ok(*b"Love Jane Forever")
+/// );
+/// ```
+pub fn base642array<B, const N: usize>(base64: B, alphabet: Base64Alphabet) -> Result<[u8; N]>
+where
+	B: AsRef<[u8]>,
+{
+	vec2array(base642bytes(base64, alphabet)?)
+}
+
+/// Deserialize base64 (standard alphabet) to [`Vec<u8>`].
+///
+/// # Examples
+/// ```
+/// use serde::Deserialize;
+///
+/// #[derive(Debug, PartialEq, Deserialize)]
+/// struct LJF {
+/// 	#[serde(deserialize_with = "array_bytes::de_base642bytes")]
+/// 	ljf: Vec<u8>,
+/// }
+///
+/// assert_eq!(
+/// 	serde_json::from_str::<LJF>(
+/// 		r#"{
+/// 		"ljf": "TG92ZSBKYW5lIEZvcmV2ZXI="
+/// 	}"#
+/// 	)
+/// 	.unwrap(),
+/// 	LJF { ljf: (*b"Love Jane Forever").to_vec() }
+/// );
+/// ```
+#[cfg(feature = "serde")]
+pub fn de_base642bytes<'de, D>(base64: D) -> CoreResult<Vec<u8>, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	let base64 = <&str>::deserialize(base64)?;
+
+	base642bytes(base64, Base64Alphabet::Standard)
+		.map_err(|_| D::Error::custom(alloc::format!("Invalid base64 str `{}`", base64)))
+}
+
+/// Deserialize a hex or base64 string holding `T`'s big-endian byte representation to `T`.
+///
+/// # Examples
+/// ```
+/// use serde::Deserialize;
+///
+/// #[derive(Debug, PartialEq, Deserialize)]
+/// struct LJF {
+/// 	#[serde(deserialize_with = "array_bytes::de_bytes2num_be")]
+/// 	ljf: u32,
+/// }
+///
+/// assert_eq!(
+/// 	serde_json::from_str::<LJF>(r#"{ "ljf": "0x00000522" }"#).unwrap(),
+/// 	LJF { ljf: 1314 }
+/// );
+/// ```
+#[cfg(feature = "serde")]
+pub fn de_bytes2num_be<'de, D, T>(bytes: D) -> CoreResult<T, D::Error>
+where
+	D: Deserializer<'de>,
+	T: NumBytes,
+{
+	let bytes = <&str>::deserialize(bytes)?;
+
+	hex_or_base642bytes(bytes)
+		.and_then(T::bytes2num_be)
+		.map_err(|_| D::Error::custom(alloc::format!("Invalid bytes str `{}`", bytes)))
+}
+
+/// Deserialize a hex or base64 string holding `T`'s little-endian byte representation to `T`.
+///
+/// # Examples
+/// ```
+/// use serde::Deserialize;
+///
+/// #[derive(Debug, PartialEq, Deserialize)]
+/// struct LJF {
+/// 	#[serde(deserialize_with = "array_bytes::de_bytes2num_le")]
+/// 	ljf: u32,
+/// }
+///
+/// assert_eq!(
+/// 	serde_json::from_str::<LJF>(r#"{ "ljf": "0x22050000" }"#).unwrap(),
+/// 	LJF { ljf: 1314 }
+/// );
+/// ```
+#[cfg(feature = "serde")]
+pub fn de_bytes2num_le<'de, D, T>(bytes: D) -> CoreResult<T, D::Error>
+where
+	D: Deserializer<'de>,
+	T: NumBytes,
+{
+	let bytes = <&str>::deserialize(bytes)?;
+
+	hex_or_base642bytes(bytes)
+		.and_then(T::bytes2num_le)
+		.map_err(|_| D::Error::custom(alloc::format!("Invalid bytes str `{}`", bytes)))
+}
+
+#[cfg(feature = "serde")]
+fn hex_or_base642bytes(s: &str) -> Result<Vec<u8>> {
+	hex2bytes(s)
+		.or_else(|_| base642bytes(s, Base64Alphabet::Standard))
+		.or_else(|_| base642bytes(s, Base64Alphabet::UrlSafe))
+}
+
+fn strip_base64_padding(base64: &[u8]) -> &[u8] {
+	let mut end = base64.len();
+
+	while end > 0 && base64[end - 1] == b'=' {
+		end -= 1;
+	}
+
+	&base64[..end]
+}
+
+/// A hex-encoded, growable byte buffer.
+///
+/// This follows the ergonomics of Cosmos SDK's `Binary`: it [`Display`](fmt::Display)s/
+/// [`Debug`](fmt::Debug)s as `0x..` via [`bytes2hex_write`], parses from the same via
+/// [`FromStr`]/`TryFrom<&str>` via [`hex2bytes`], exposes its raw bytes through [`AsRef<[u8]>`]/
+/// [`Deref`], and, behind the `serde` feature, (de)serializes as a hex string automatically, so a
+/// field of this type needs no per-field `serialize_with`/`deserialize_with`.
+///
+/// # Examples
+/// ```
+/// let hex = "0x4c6f7665204a616e6520466f7265766572".parse::<array_bytes::Hex>().unwrap();
+///
+/// assert_eq!(hex.as_ref(), b"Love Jane Forever");
+/// assert_eq!(hex.to_string(), "0x4c6f7665204a616e6520466f7265766572");
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Hex(Vec<u8>);
+impl Hex {
+	/// Wrap raw bytes as [`Hex`].
+	pub fn new(bytes: Vec<u8>) -> Self {
+		Self(bytes)
+	}
+
+	/// Unwrap the inner bytes.
+	pub fn into_inner(self) -> Vec<u8> {
+		self.0
+	}
+}
+impl fmt::Debug for Hex {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt::Display::fmt(self, f)
+	}
+}
+impl fmt::Display for Hex {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		bytes2hex_write("0x", &self.0, f)
+	}
+}
+impl FromStr for Hex {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self> {
+		Ok(Self(hex2bytes(s)?))
+	}
+}
+impl TryFrom<&str> for Hex {
+	type Error = Error;
+
+	fn try_from(s: &str) -> Result<Self> {
+		s.parse()
+	}
+}
+impl From<Vec<u8>> for Hex {
+	fn from(bytes: Vec<u8>) -> Self {
+		Self(bytes)
+	}
+}
+impl AsRef<[u8]> for Hex {
+	fn as_ref(&self) -> &[u8] {
+		&self.0
+	}
+}
+impl Deref for Hex {
+	type Target = [u8];
+
+	fn deref(&self) -> &[u8] {
+		&self.0
+	}
+}
+impl ToHex for Hex {
+	fn to_hex(&self, prefix: &str) -> String {
+		bytes2hex(prefix, &self.0)
+	}
+}
+#[cfg(feature = "serde")]
+impl Serialize for Hex {
+	fn serialize<S>(&self, serializer: S) -> CoreResult<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		hex_serialize(self, serializer)
+	}
+}
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Hex {
+	fn deserialize<D>(deserializer: D) -> CoreResult<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		let hex = <&str>::deserialize(deserializer)?;
+
+		hex2bytes(hex)
+			.map(Self)
+			.map_err(|_| DeError::custom(alloc::format!("Invalid hex str `{}`", hex)))
+	}
+}
+
+/// Just like [`Hex`] but backed by a fixed-size `[u8; N]`.
+///
+/// # Examples
+/// ```
+/// let hex =
+/// 	"0x4c6f7665204a616e6520466f7265766572".parse::<array_bytes::HexArray<17>>().unwrap();
+///
+/// assert_eq!(hex.as_ref(), b"Love Jane Forever");
+/// assert_eq!(hex.to_string(), "0x4c6f7665204a616e6520466f7265766572");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HexArray<const N: usize>([u8; N]);
+impl<const N: usize> HexArray<N> {
+	/// Wrap a raw array as [`HexArray`].
+	pub fn new(array: [u8; N]) -> Self {
+		Self(array)
+	}
+
+	/// Unwrap the inner array.
+	pub fn into_inner(self) -> [u8; N] {
+		self.0
+	}
+}
+impl<const N: usize> fmt::Debug for HexArray<N> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt::Display::fmt(self, f)
+	}
+}
+impl<const N: usize> fmt::Display for HexArray<N> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		bytes2hex_write("0x", self.0, f)
+	}
+}
+impl<const N: usize> FromStr for HexArray<N> {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self> {
+		Ok(Self(hex2array(s)?))
+	}
+}
+impl<const N: usize> TryFrom<&str> for HexArray<N> {
+	type Error = Error;
+
+	fn try_from(s: &str) -> Result<Self> {
+		s.parse()
+	}
+}
+impl<const N: usize> From<[u8; N]> for HexArray<N> {
+	fn from(array: [u8; N]) -> Self {
+		Self(array)
+	}
+}
+impl<const N: usize> AsRef<[u8]> for HexArray<N> {
+	fn as_ref(&self) -> &[u8] {
+		&self.0
+	}
+}
+impl<const N: usize> Deref for HexArray<N> {
+	type Target = [u8];
+
+	fn deref(&self) -> &[u8] {
+		&self.0
+	}
+}
+impl<const N: usize> ToHex for HexArray<N> {
+	fn to_hex(&self, prefix: &str) -> String {
+		bytes2hex(prefix, self.0)
+	}
+}
+#[cfg(feature = "serde")]
+impl<const N: usize> Serialize for HexArray<N> {
+	fn serialize<S>(&self, serializer: S) -> CoreResult<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		hex_serialize(self, serializer)
+	}
+}
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> Deserialize<'de> for HexArray<N> {
+	fn deserialize<D>(deserializer: D) -> CoreResult<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		let hex = <&str>::deserialize(deserializer)?;
+
+		hex2array(hex)
+			.map(Self)
+			.map_err(|_| DeError::custom(alloc::format!("Invalid hex str `{}`", hex)))
+	}
+}
+
 fn strip_0x(hex: &str) -> &str {
 	if let Some(hex) = hex.strip_prefix("0x") {
 		hex
@@ -0,0 +1,55 @@
+use crate::*;
+
+#[test]
+fn base642bytes_should_round_trip_url_safe_alphabet() {
+	let bytes = b"Love Jane Forever";
+	let base64 = bytes2base64(bytes, Base64Alphabet::UrlSafe);
+
+	assert_eq!(base64, "TG92ZSBKYW5lIEZvcmV2ZXI=");
+	assert_eq!(base642bytes(&base64, Base64Alphabet::UrlSafe), Ok(bytes.to_vec()));
+}
+
+#[test]
+fn base642bytes_should_reject_invalid_character() {
+	assert_eq!(
+		base642bytes("TG9^ZSBKYW5l", Base64Alphabet::Standard),
+		Err(Error::InvalidCharacter { character: '^', index: 3 })
+	);
+}
+
+#[test]
+fn base642bytes_should_reject_4n_plus_1_length() {
+	// 5 (unpadded) chars is `4 * 1 + 1`.
+	assert_eq!(base642bytes("TG92Z", Base64Alphabet::Standard), Err(Error::InvalidLength));
+}
+
+#[test]
+fn num_bytes_should_round_trip_unsigned() {
+	assert_eq!(u32::bytes2num_be(1314_u32.num2bytes_be()), Ok(1314));
+	assert_eq!(u32::bytes2num_le(1314_u32.num2bytes_le()), Ok(1314));
+}
+
+#[test]
+fn num_bytes_should_round_trip_signed() {
+	assert_eq!(i32::bytes2num_be((-1314_i32).num2bytes_be()), Ok(-1314));
+	assert_eq!(i32::bytes2num_le((-1314_i32).num2bytes_le()), Ok(-1314));
+	assert_eq!(i32::bytes2num_be(i32::MIN.num2bytes_be()), Ok(i32::MIN));
+}
+
+#[test]
+fn num_bytes_should_reject_mismatched_length() {
+	assert_eq!(
+		u32::bytes2num_be(&[0_u8, 0, 0][..]),
+		Err(Error::MismatchedLength { expect: core::mem::size_of::<u32>() })
+	);
+}
+
+#[test]
+fn to_hex_from_hex_should_round_trip_signed_extremes() {
+	assert_eq!(i8::MIN.to_hex("0x"), "-0x80");
+	assert_eq!(i8::try_from_hex(i8::MIN.to_hex("0x")), Ok(i8::MIN));
+	assert_eq!(i128::MIN.to_hex("0x"), "-0x80000000000000000000000000000000");
+	assert_eq!(i128::try_from_hex(i128::MIN.to_hex("0x")), Ok(i128::MIN));
+	assert_eq!((-2_i32).to_hex("0x"), "-0x2");
+	assert_eq!(i32::try_from_hex("-0x2"), Ok(-2));
+}